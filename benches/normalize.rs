@@ -0,0 +1,30 @@
+use std::hint::black_box;
+use std::time::Instant;
+
+use ecdsa256::curve::{JacobianPoint, generator, normalize_points};
+
+const POINTS: usize = 256;
+const ROUNDS: u32 = 20;
+
+fn main() {
+    let mut jac = vec![JacobianPoint::from(&generator())];
+    for _ in 1..POINTS {
+        jac.push(jac.last().unwrap().double());
+    }
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        let affine: Vec<_> = jac.iter().map(|p| p.to_affine()).collect();
+        black_box(affine);
+    }
+    let per_point = start.elapsed() / ROUNDS;
+
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        black_box(normalize_points(black_box(&jac)));
+    }
+    let batch = start.elapsed() / ROUNDS;
+
+    println!("to_affine x {POINTS}:        {per_point:?}");
+    println!("normalize_points ({POINTS}): {batch:?}");
+}
@@ -1,5 +1,5 @@
-use crate::field::Fe;
-use crypto_bigint::{ConstChoice, U256};
+use crate::field::{Fe, batch_invert};
+use crypto_bigint::U256;
 
 // secp256k1 生成元 G 的坐标
 pub const GX: &str = "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798";
@@ -8,13 +8,17 @@ pub const GY: &str = "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB
 // 曲线阶 n
 pub const N: &str = "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141";
 
+/// 生成元 G（编译期计算好 Montgomery 形式）
+pub const GENERATOR: Point = Point {
+    cords: Some((
+        Fe::new(&U256::from_be_hex(GX)),
+        Fe::new(&U256::from_be_hex(GY)),
+    )),
+};
+
 /// 获取生成元 G
 pub fn generator() -> Point {
-    let gx = U256::from_be_hex(GX);
-    let gy = U256::from_be_hex(GY);
-    Point {
-        cords: Some((Fe::new(&gx), Fe::new(&gy))),
-    }
+    GENERATOR
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -37,6 +41,11 @@ impl Point {
     pub fn y(&self) -> Option<U256> {
         self.cords.as_ref().map(|(_, y)| y.retrieve())
     }
+
+    /// 检查点是否为生成元 G（公钥等于 G 意味着私钥为 1）
+    pub fn is_generator(&self) -> bool {
+        *self == GENERATOR
+    }
 }
 
 impl Point {
@@ -92,12 +101,13 @@ impl core::ops::Add for Point {
 impl core::ops::Mul<U256> for Point {
     type Output = Self;
 
+    #[allow(clippy::suspicious_arithmetic_impl)]
     fn mul(self, scalar: U256) -> Self::Output {
         let mut result = Point { cords: None };
         let mut addend = self;
 
         for i in 0..256 {
-            if scalar.bit(i) == ConstChoice::TRUE {
+            if scalar.bit(i).to_bool() {
                 result = result + addend.clone();
             }
             addend = addend.double();
@@ -107,11 +117,191 @@ impl core::ops::Mul<U256> for Point {
     }
 }
 
+/// Jacobian 坐标点 (X, Y, Z)，对应仿射坐标 (X/Z², Y/Z³)；Z = 0 表示无穷远点
+#[derive(Clone, Copy, Debug)]
+pub struct JacobianPoint {
+    pub x: Fe,
+    pub y: Fe,
+    pub z: Fe,
+}
+
+impl From<&Point> for JacobianPoint {
+    fn from(p: &Point) -> Self {
+        match p.cords {
+            Some((x, y)) => JacobianPoint { x, y, z: Fe::ONE },
+            None => JacobianPoint::infinity(),
+        }
+    }
+}
+
+impl JacobianPoint {
+    pub fn infinity() -> Self {
+        JacobianPoint {
+            x: Fe::ONE,
+            y: Fe::ONE,
+            z: Fe::ZERO,
+        }
+    }
+
+    /// 检查点是否为无穷远点
+    pub fn is_infinity(&self) -> bool {
+        self.z == Fe::ZERO
+    }
+
+    /// 倍点（dbl-2009-l，a = 0），无需求逆
+    pub fn double(&self) -> Self {
+        if self.is_infinity() || self.y == Fe::ZERO {
+            return JacobianPoint::infinity();
+        }
+
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = ((self.x + b).square() - a - c).double();
+        let e = a.double() + a;
+        let f = e.square();
+
+        let x3 = f - d.double();
+        let y3 = e * (d - x3) - c.double().double().double();
+        let z3 = (self.y * self.z).double();
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// 混合加法：Jacobian 点加仿射点（madd-2007-bl，Z2 = 1），无需求逆
+    pub fn add_affine(&self, other: &Point) -> Self {
+        let Some((x2, y2)) = other.cords else {
+            return *self;
+        };
+        if self.is_infinity() {
+            return JacobianPoint::from(other);
+        }
+
+        let z1z1 = self.z.square();
+        let u2 = x2 * z1z1;
+        let s2 = y2 * self.z * z1z1;
+        let h = u2 - self.x;
+        let r = (s2 - self.y).double();
+        if h == Fe::ZERO {
+            return if r == Fe::ZERO {
+                self.double()
+            } else {
+                JacobianPoint::infinity()
+            };
+        }
+
+        let hh = h.square();
+        let i = hh.double().double();
+        let j = h * i;
+        let v = self.x * i;
+
+        let x3 = r.square() - j - v.double();
+        let y3 = r * (v - x3) - (self.y * j).double();
+        let z3 = (self.z + h).square() - z1z1 - hh;
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// 转换为仿射坐标（每个点一次求逆；批量转换请用 normalize_points）
+    pub fn to_affine(&self) -> Point {
+        if self.is_infinity() {
+            return Point { cords: None };
+        }
+        let z_inv = self.z.invert().unwrap();
+        affine_from_z_inv(self, &z_inv)
+    }
+}
+
+impl core::ops::Add for JacobianPoint {
+    type Output = Self;
+
+    /// 一般加法（add-2007-bl），无需求逆
+    fn add(self, other: Self) -> Self::Output {
+        if self.is_infinity() {
+            return other;
+        }
+        if other.is_infinity() {
+            return self;
+        }
+
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let u1 = self.x * z2z2;
+        let u2 = other.x * z1z1;
+        let s1 = self.y * other.z * z2z2;
+        let s2 = other.y * self.z * z1z1;
+        let h = u2 - u1;
+        let r = (s2 - s1).double();
+        if h == Fe::ZERO {
+            return if r == Fe::ZERO {
+                self.double()
+            } else {
+                JacobianPoint::infinity()
+            };
+        }
+
+        let i = h.double().square();
+        let j = h * i;
+        let v = u1 * i;
+
+        let x3 = r.square() - j - v.double();
+        let y3 = r * (v - x3) - (s1 * j).double();
+        let z3 = ((self.z + other.z).square() - z1z1 - z2z2) * h;
+        JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+fn affine_from_z_inv(p: &JacobianPoint, z_inv: &Fe) -> Point {
+    let z_inv2 = z_inv.square();
+    let z_inv3 = z_inv2 * z_inv;
+    Point {
+        cords: Some((p.x * z_inv2, p.y * z_inv3)),
+    }
+}
+
+/// 批量转换为仿射坐标：收集所有非零 Z 一次性批量求逆，整批只做一次域上求逆。
+/// 目前 Point 的标量乘法与 ecdsa::verify 仍使用仿射坐标，尚未接入此路径
+pub fn normalize_points(jac: &[JacobianPoint]) -> Vec<Point> {
+    let zs: Vec<Fe> = jac
+        .iter()
+        .filter(|p| !p.is_infinity())
+        .map(|p| p.z)
+        .collect();
+    let mut z_invs = batch_invert(&zs)
+        .expect("non-infinity points have non-zero Z")
+        .into_iter();
+
+    jac.iter()
+        .map(|p| {
+            if p.is_infinity() {
+                Point { cords: None }
+            } else {
+                affine_from_z_inv(p, &z_invs.next().unwrap())
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use crypto_bigint::U256;
 
-    use crate::{curve::Point, field::Fe};
+    use crate::{
+        curve::{JacobianPoint, Point, generator, normalize_points},
+        ecdsa::public_key_from_private,
+        field::Fe,
+        scalar::Scalar,
+    };
 
     #[test]
     fn test_point_addition() {
@@ -160,4 +350,74 @@ mod tests {
         assert_eq!(k2.cords.unwrap().0.retrieve(), expected_x2);
         assert_eq!(k2.cords.unwrap().1.retrieve(), expected_y2);
     }
+
+    #[test]
+    fn test_normalize_points() {
+        let g = generator();
+        let mut jac = vec![JacobianPoint::from(&g)];
+        for _ in 0..15 {
+            jac.push(jac.last().unwrap().double());
+        }
+        jac.push(JacobianPoint::infinity());
+
+        let batch = normalize_points(&jac);
+        assert_eq!(batch.len(), jac.len());
+        for (p, affine) in jac.iter().zip(batch.iter()) {
+            assert_eq!(*affine, p.to_affine());
+        }
+
+        // 与仿射坐标倍点结果一致
+        let mut expected = g;
+        for affine in batch.iter().take(16) {
+            assert_eq!(*affine, expected);
+            expected = expected.double();
+        }
+        assert!(batch[16].is_infinity());
+    }
+
+    #[test]
+    fn test_is_generator() {
+        let one = Scalar::new(&U256::from_u64(1));
+        assert!(public_key_from_private(&one).is_generator());
+
+        let priv_key = Scalar::new(&U256::from_be_hex(
+            "C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721",
+        ));
+        assert!(!public_key_from_private(&priv_key).is_generator());
+        assert!(!Point { cords: None }.is_generator());
+    }
+
+    #[test]
+    fn test_jacobian_add() {
+        let g = generator();
+        let g2 = g.double();
+        let g3 = g2.clone() + g.clone();
+
+        let jg = JacobianPoint::from(&g);
+        let jg2 = jg.double();
+        assert_eq!((jg2 + jg).to_affine(), g3);
+        assert_eq!(jg2.add_affine(&g).to_affine(), g3);
+
+        // 两个 Z 均不为 1 且不同
+        let jg4 = jg2.double();
+        assert_eq!((jg2 + jg4).to_affine(), g2.clone() + g2.double());
+
+        // P + P 退化为倍点
+        assert_eq!((jg2 + jg2).to_affine(), g2.double());
+        assert_eq!(jg2.add_affine(&g2).to_affine(), g2.double());
+
+        // P + (-P) = O
+        let neg_g = JacobianPoint {
+            y: Fe::ZERO - jg.y,
+            ..jg
+        };
+        assert!((jg + neg_g).is_infinity());
+        assert!(neg_g.add_affine(&g).is_infinity());
+
+        // 与无穷远点相加
+        let inf = JacobianPoint::infinity();
+        assert_eq!((inf + jg2).to_affine(), g2);
+        assert_eq!(inf.add_affine(&g).to_affine(), g);
+        assert_eq!(jg2.add_affine(&Point { cords: None }).to_affine(), g2);
+    }
 }
@@ -0,0 +1,101 @@
+use rfc6979::HmacDrbg;
+use sha2::Sha256;
+
+/// HMAC-SHA256 DRBG, the same construction used for RFC6979 nonces.
+///
+/// Exposed so custom deterministic constructions (e.g. Schnorr nonces, VRF
+/// challenges) can reuse it instead of re-deriving the RFC6979 plumbing.
+pub struct Drbg {
+    inner: HmacDrbg<Sha256>,
+}
+
+impl Drbg {
+    pub fn new(seed: &[u8], nonce: &[u8], personalization: &[u8]) -> Self {
+        Self {
+            inner: HmacDrbg::new(seed, nonce, personalization),
+        }
+    }
+
+    /// Fill `out` with one HMAC_DRBG generate step, then update K and V.
+    ///
+    /// This is not a byte stream: each call is a separate generate, so two
+    /// 32-byte fills differ from one 64-byte fill. Callers that must
+    /// interoperate have to split their requests the same way.
+    pub fn fill(&mut self, out: &mut [u8]) {
+        self.inner.fill_bytes(out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ecdsa::{sign, sign_hash};
+    use crate::scalar::Scalar;
+    use crypto_bigint::U256;
+
+    #[test]
+    fn test_drbg_deterministic() {
+        let mut a = Drbg::new(&[0x11; 32], &[0x22; 32], b"test");
+        let mut b = Drbg::new(&[0x11; 32], &[0x22; 32], b"test");
+
+        for _ in 0..4 {
+            let mut out_a = [0u8; 48];
+            let mut out_b = [0u8; 48];
+            a.fill(&mut out_a);
+            b.fill(&mut out_b);
+            assert_eq!(out_a, out_b);
+        }
+    }
+
+    #[test]
+    fn test_drbg_personalization() {
+        let mut a = Drbg::new(&[0x11; 32], &[0x22; 32], b"a");
+        let mut b = Drbg::new(&[0x11; 32], &[0x22; 32], b"b");
+
+        let mut out_a = [0u8; 32];
+        let mut out_b = [0u8; 32];
+        a.fill(&mut out_a);
+        b.fill(&mut out_b);
+        assert_ne!(out_a, out_b);
+    }
+
+    #[test]
+    fn test_drbg_fill_is_not_a_stream() {
+        let mut a = Drbg::new(&[0x11; 32], &[0x22; 32], &[]);
+        let mut b = Drbg::new(&[0x11; 32], &[0x22; 32], &[]);
+
+        let mut split = [0u8; 64];
+        a.fill(&mut split[..32]);
+        a.fill(&mut split[32..]);
+
+        let mut whole = [0u8; 64];
+        b.fill(&mut whole);
+
+        assert_eq!(split[..32], whole[..32]);
+        assert_ne!(split, whole);
+    }
+
+    #[test]
+    fn test_drbg_rfc6979_k() {
+        // Same inputs as ecdsa::tests::test_sign_hash_rfc6979
+        let priv_key = Scalar::new(&U256::from_u64(0x12345));
+        let mut msg_hash = [0u8; 32];
+        msg_hash[28..].copy_from_slice(&[0xDE, 0xAD, 0xBE, 0xEF]);
+
+        let mut drbg = Drbg::new(&priv_key.retrieve().to_be_bytes(), &msg_hash, &[]);
+        let mut k_bytes = [0u8; 32];
+        drbg.fill(&mut k_bytes);
+
+        let expected =
+            U256::from_be_hex("8E9A9B1540A4177BF0C724B34407101CB0B6EEF56E0B7C80CC1B91911F1F7977");
+        assert_eq!(U256::from_be_slice(&k_bytes), expected);
+
+        // sign_hash uses exactly this k
+        let k = Scalar::new(&expected);
+        let msg_scalar = Scalar::new(&U256::from_be_slice(&msg_hash));
+        assert_eq!(
+            sign(&priv_key, &msg_scalar, &k),
+            sign_hash(&priv_key, &msg_hash)
+        );
+    }
+}
@@ -1,8 +1,8 @@
 use crate::curve::{N, Point, generator};
+use crate::drbg::Drbg;
+use crate::keccak::keccak256;
 use crate::scalar::Scalar;
 use crypto_bigint::U256;
-use rfc6979::HmacDrbg;
-use sha2::Sha256;
 
 /// ECDSA signature with recovery id
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -36,7 +36,7 @@ impl Signature {
         if s_val > half_n {
             let new_s = Scalar::new(&n.wrapping_sub(&s_val));
             Self {
-                r: self.r.clone(),
+                r: self.r,
                 s: new_s,
                 v: self.v ^ 1,
             }
@@ -60,11 +60,11 @@ impl Signature {
 fn generate_k_rfc6979(priv_key: &Scalar, msg_hash: &[u8; 32]) -> Scalar {
     let priv_bytes = priv_key.retrieve().to_be_bytes();
     let n = U256::from_be_hex(N);
-    let mut drbg = HmacDrbg::<Sha256>::new(&priv_bytes, msg_hash, &[]);
+    let mut drbg = Drbg::new(&priv_bytes, msg_hash, &[]);
 
     loop {
         let mut k_bytes = [0u8; 32];
-        drbg.fill_bytes(&mut k_bytes);
+        drbg.fill(&mut k_bytes);
         let k_val = U256::from_be_slice(&k_bytes);
 
         if k_val > U256::ZERO && k_val < n {
@@ -97,8 +97,8 @@ pub fn sign(priv_key: &Scalar, msg_hash: &Scalar, k: &Scalar) -> Option<Signatur
     }
     let k_inv = k_inv_opt.unwrap();
 
-    let r_times_d = r.clone() * priv_key.clone();
-    let z_plus_rd = msg_hash.clone() + r_times_d;
+    let r_times_d = r * *priv_key;
+    let z_plus_rd = *msg_hash + r_times_d;
     let s = k_inv * z_plus_rd;
 
     if s.retrieve() == U256::ZERO {
@@ -115,6 +115,16 @@ pub fn sign_hash(priv_key: &Scalar, msg_hash: &[u8; 32]) -> Option<Signature> {
     sign(priv_key, &msg_scalar, &k)
 }
 
+/// ECDSA sign over the Keccak-256 digest of `msg`.
+///
+/// Only the message hash is Keccak-256; the RFC6979 DRBG deliberately stays
+/// HMAC-SHA256, the nonce construction libsecp256k1 (geth) and ethers use.
+/// Output is tested to match `k256::ecdsa` for the same prehash; it has not
+/// been checked against a geth or ethers signature.
+pub fn sign_keccak(priv_key: &Scalar, msg: &[u8]) -> Option<Signature> {
+    sign_hash(priv_key, &keccak256(msg))
+}
+
 /// ECDSA verify signature
 pub fn verify(pub_key: &Point, msg_hash: &Scalar, sig: &Signature) -> bool {
     if !sig.is_valid() || pub_key.is_infinity() {
@@ -131,8 +141,8 @@ pub fn verify(pub_key: &Point, msg_hash: &Scalar, sig: &Signature) -> bool {
     }
     let s_inv = s_inv_opt.unwrap();
 
-    let u1 = z.clone() * s_inv.clone();
-    let u2 = r.clone() * s_inv;
+    let u1 = *z * s_inv;
+    let u2 = *r * s_inv;
 
     let g = generator();
     let r_point = g * u1.retrieve() + pub_key.clone() * u2.retrieve();
@@ -147,6 +157,12 @@ pub fn verify(pub_key: &Point, msg_hash: &Scalar, sig: &Signature) -> bool {
     }
 }
 
+/// ECDSA verify signature over the Keccak-256 digest of `msg`
+pub fn verify_keccak(pub_key: &Point, msg: &[u8], sig: &Signature) -> bool {
+    let msg_scalar = Scalar::new(&U256::from_be_slice(&keccak256(msg)));
+    verify(pub_key, &msg_scalar, sig)
+}
+
 /// Derive public key from private key
 pub fn public_key_from_private(priv_key: &Scalar) -> Point {
     generator() * priv_key.retrieve()
@@ -211,7 +227,7 @@ mod tests {
         let r = Scalar::new(&U256::from_u64(12345));
         let s = Scalar::new(&high_s);
 
-        let sig = Signature::new(r.clone(), s, 0);
+        let sig = Signature::new(r, s, 0);
         let normalized = sig.normalize();
 
         assert!(normalized.s.retrieve() <= half_n, "s should be <= n/2");
@@ -307,13 +323,52 @@ mod tests {
         let sig = sign(&priv_key, &msg_hash, &k).expect("sign failed");
 
         // Ethereum mainnet chain_id = 1
-        let v_mainnet = sig.v_eip155(1);
+        let chain_id = 1u64;
+        let v_mainnet = sig.v_eip155(chain_id);
         assert!(
             v_mainnet == 37 || v_mainnet == 38,
             "mainnet v should be 37 or 38"
         );
 
         // v_eip155 = 35 + chain_id * 2 + recovery_id
-        assert_eq!(v_mainnet, 35 + 1 * 2 + sig.v as u64, "EIP-155 v calc error");
+        assert_eq!(
+            v_mainnet,
+            35 + chain_id * 2 + sig.v as u64,
+            "EIP-155 v calc error"
+        );
+    }
+
+    #[test]
+    fn test_sign_keccak_eip191_vector() {
+        let priv_key = Scalar::new(&U256::from_be_hex(
+            "0123456789012345678901234567890123456789012345678901234567890123",
+        ));
+        let pub_key = public_key_from_private(&priv_key);
+
+        // EIP-191 personal message, as hashed by ethers' signMessage
+        let mut msg = b"\x19Ethereum Signed Message:\n11".to_vec();
+        msg.extend_from_slice(b"Hello World");
+
+        // Digest from the ethers docs: hashMessage("Hello World")
+        let expected_hash =
+            U256::from_be_hex("A1DE988600A42C4B4AB089B619297C17D53CFFAE5D5120D82D8A92D0BB3B78F2");
+        assert_eq!(keccak256(&msg), expected_hash.to_be_bytes().as_ref());
+
+        // Expected r/s/v were computed offline with k256::ecdsa (RFC6979 with an
+        // HMAC-SHA256 DRBG, low-s), not taken from an ethers signature
+        let sig = sign_keccak(&priv_key, &msg).expect("keccak sign failed");
+        let expected_r =
+            U256::from_be_hex("E0ED34FBBE927A58267CE2E8067A611C69869E20E731BC99187A8BC97058664C");
+        let expected_s =
+            U256::from_be_hex("16DE07F7660F06CE0985D1D8E063726783033FDA59B307897F26A21392D62B3A");
+        assert_eq!(sig.r.retrieve(), expected_r, "r mismatch");
+        assert_eq!(sig.s.retrieve(), expected_s, "s mismatch");
+        assert_eq!(sig.v_legacy(), 28, "v mismatch");
+
+        assert!(verify_keccak(&pub_key, &msg, &sig), "keccak verify failed");
+        assert!(
+            !verify_keccak(&pub_key, b"Hello World", &sig),
+            "unprefixed message should fail"
+        );
     }
 }
@@ -6,6 +6,27 @@ const_monty_params!(Coordinate, U256, P);
 
 const_monty_form!(Fe, Coordinate);
 
+/// 批量求逆（Montgomery trick）：整批只做一次域上求逆。任一元素为 0 时返回 None
+pub fn batch_invert(values: &[Fe]) -> Option<Vec<Fe>> {
+    // prefix[i] = values[0] * ... * values[i]
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = Fe::ONE;
+    for v in values {
+        acc *= v;
+        prefix.push(acc);
+    }
+
+    let mut inv: Fe = Option::from(acc.invert())?;
+    let mut out = vec![Fe::ZERO; values.len()];
+    for i in (0..values.len()).rev() {
+        let before = if i == 0 { Fe::ONE } else { prefix[i - 1] };
+        out[i] = inv * before;
+        inv *= values[i];
+    }
+
+    Some(out)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -34,4 +55,19 @@ mod test {
         let one = fe_a.mul(&fe_a_inv);
         assert_eq!(one, Fe::new(&U256::from_u64(1)));
     }
+
+    #[test]
+    fn test_batch_invert() {
+        let values: Vec<Fe> = (1..=8u64)
+            .map(|i| Fe::new(&U256::from_u64(i * 31)))
+            .collect();
+        let inverses = batch_invert(&values).unwrap();
+
+        for (v, inv) in values.iter().zip(inverses.iter()) {
+            assert_eq!(*inv, v.invert().unwrap());
+        }
+
+        assert!(batch_invert(&[]).unwrap().is_empty());
+        assert!(batch_invert(&[Fe::ONE, Fe::ZERO]).is_none());
+    }
 }
@@ -0,0 +1,158 @@
+/// Keccak-256 as used by Ethereum (original Keccak padding `0x01`, not the
+/// NIST SHA3-256 padding `0x06`).
+const RATE: usize = 136;
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001,
+    0x0000000000008082,
+    0x800000000000808A,
+    0x8000000080008000,
+    0x000000000000808B,
+    0x0000000080000001,
+    0x8000000080008081,
+    0x8000000000008009,
+    0x000000000000008A,
+    0x0000000000000088,
+    0x0000000080008009,
+    0x000000008000000A,
+    0x000000008000808B,
+    0x800000000000008B,
+    0x8000000000008089,
+    0x8000000000008003,
+    0x8000000000008002,
+    0x8000000000000080,
+    0x000000000000800A,
+    0x800000008000000A,
+    0x8000000080008081,
+    0x8000000000008080,
+    0x0000000080000001,
+    0x8000000080008008,
+];
+
+const ROTATIONS: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+const PI_LANES: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// Keccak-f[1600] permutation
+fn keccak_f(state: &mut [u64; 25]) {
+    for rc in ROUND_CONSTANTS {
+        // theta
+        let mut c = [0u64; 5];
+        for x in 0..5 {
+            c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+
+        // rho + pi
+        let mut last = state[1];
+        for (&lane, &rot) in PI_LANES.iter().zip(ROTATIONS.iter()) {
+            let tmp = state[lane];
+            state[lane] = last.rotate_left(rot);
+            last = tmp;
+        }
+
+        // chi
+        for y in 0..5 {
+            let row = [
+                state[5 * y],
+                state[5 * y + 1],
+                state[5 * y + 2],
+                state[5 * y + 3],
+                state[5 * y + 4],
+            ];
+            for x in 0..5 {
+                state[5 * y + x] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+            }
+        }
+
+        // iota
+        state[0] ^= rc;
+    }
+}
+
+fn absorb_block(state: &mut [u64; 25], block: &[u8]) {
+    for (lane, chunk) in state.iter_mut().zip(block.chunks_exact(8)) {
+        *lane ^= u64::from_le_bytes(chunk.try_into().unwrap());
+    }
+    keccak_f(state);
+}
+
+/// Compute the Keccak-256 digest of `data`
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut state = [0u64; 25];
+
+    let mut blocks = data.chunks_exact(RATE);
+    for block in &mut blocks {
+        absorb_block(&mut state, block);
+    }
+
+    let rest = blocks.remainder();
+    let mut last = [0u8; RATE];
+    last[..rest.len()].copy_from_slice(rest);
+    last[rest.len()] ^= 0x01;
+    last[RATE - 1] ^= 0x80;
+    absorb_block(&mut state, &last);
+
+    let mut out = [0u8; 32];
+    for (chunk, lane) in out.chunks_exact_mut(8).zip(state.iter()) {
+        chunk.copy_from_slice(&lane.to_le_bytes());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crypto_bigint::U256;
+
+    #[test]
+    fn test_keccak256_empty() {
+        let expected =
+            U256::from_be_hex("C5D2460186F7233C927E7DB2DCC703C0E500B653CA82273B7BFAD8045D85A470");
+        assert_eq!(keccak256(b""), expected.to_be_bytes().as_ref());
+    }
+
+    #[test]
+    fn test_keccak256_abc() {
+        let expected =
+            U256::from_be_hex("4E03657AEA45A94FC7D47BA826C8D667C0D1E6E33A64A036EC44F58FA12D6C45");
+        assert_eq!(keccak256(b"abc"), expected.to_be_bytes().as_ref());
+    }
+
+    fn pattern(len: usize) -> Vec<u8> {
+        (0..len).map(|i| i as u8).collect()
+    }
+
+    #[test]
+    fn test_keccak256_pad_shares_byte() {
+        // 135 bytes: 0x01 and 0x80 padding land in the same byte
+        let expected =
+            U256::from_be_hex("CBDFD9DEE5FAAD3818D6B06F95A219FD290B0E1706F6A82E5A595B9CE9FACA62");
+        assert_eq!(keccak256(&pattern(135)), expected.to_be_bytes().as_ref());
+    }
+
+    #[test]
+    fn test_keccak256_full_block() {
+        // 136 bytes: one full block, padding goes into a second block
+        let expected =
+            U256::from_be_hex("7CE759F1AB7F9CE437719970C26B0A66FF11FE3E38E17DF89CF5D29C7D7F807E");
+        assert_eq!(keccak256(&pattern(136)), expected.to_be_bytes().as_ref());
+    }
+
+    #[test]
+    fn test_keccak256_multi_block() {
+        // 300 bytes: two full blocks plus a partial one
+        let expected =
+            U256::from_be_hex("A679E749A6AF300C36E7FF2255D220864EAB27B382F9CFDC5AA4D13563BA36FF");
+        assert_eq!(keccak256(&pattern(300)), expected.to_be_bytes().as_ref());
+    }
+}
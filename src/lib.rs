@@ -1,4 +1,7 @@
 pub mod curve;
+pub mod drbg;
 pub mod ecdsa;
 pub mod field;
+pub mod keccak;
 pub mod scalar;
+pub mod structured;